@@ -0,0 +1,39 @@
+use biome_diagnostics::{Diagnostic, Error};
+use std::fmt::Debug;
+
+/// Errors that can happen when loading or resolving the `biome.json`/`biome.jsonc` configuration.
+#[derive(Debug, Diagnostic)]
+pub enum ConfigurationDiagnostic {
+    /// Thrown when a configuration file, or one of its `extends` entries, can't be loaded.
+    CantLoadExtendFile(CantLoadExtendFile),
+}
+
+impl ConfigurationDiagnostic {
+    pub fn cant_load_extend_file(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::CantLoadExtendFile(CantLoadExtendFile {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+}
+
+/// Thrown when Biome can't resolve or parse one of the `extends` entries of a configuration.
+///
+/// `path` holds the offending specifier, which can either be a relative path (e.g. `"./base.json"`)
+/// or a bare npm specifier (e.g. `"@my-org/biome-config/recommended"`).
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "configuration",
+    severity = Error,
+    message = "Biome couldn't load the extended configuration file."
+)]
+pub struct CantLoadExtendFile {
+    pub path: String,
+    pub reason: String,
+}
+
+impl From<CantLoadExtendFile> for Error {
+    fn from(diagnostic: CantLoadExtendFile) -> Self {
+        Error::from(ConfigurationDiagnostic::CantLoadExtendFile(diagnostic))
+    }
+}
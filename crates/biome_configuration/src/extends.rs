@@ -0,0 +1,303 @@
+//! Resolution of the `extends` field of a configuration.
+//!
+//! An entry of `extends` can either be a relative path to another configuration file
+//! (`"./base.json"`, `"../shared/biome.jsonc"`), or a bare npm specifier that points at a package
+//! exposing one or more Biome presets through its `package.json#exports` map, e.g.
+//! `"@my-org/biome-config/recommended"`. This module resolves every entry to a file on disk,
+//! honoring the `exports` condition names Node itself would try (`"node"`, `"import"`,
+//! `"default"`), reads and merges the partials it finds in declaration order, and rejects cycles.
+
+use crate::diagnostics::ConfigurationDiagnostic;
+use crate::PartialConfiguration;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::Error;
+use biome_json_parser::JsonParserOptions;
+use oxc_resolver::{ResolveOptions, Resolver};
+use std::path::{Path, PathBuf};
+
+/// Condition names tried, in order, when an `extends` entry is a bare npm specifier and its
+/// package exposes an `exports` map. Mirrors the conditions Node itself would try.
+const RESOLVE_CONDITIONS: &[&str] = &["node", "import", "default"];
+
+/// Resolves every entry of `configuration.extends`, relative to `base_path`, in declaration
+/// order, and folds the resulting partials into `configuration` (entries listed later win over
+/// entries listed earlier, and the original `configuration` wins over all of them).
+///
+/// Cycles between extended files are detected and reported through
+/// [ConfigurationDiagnostic::CantLoadExtendFile]. Only the current ancestor chain is tracked, so
+/// a diamond - two entries that both (transitively) extend the same shared preset - is not
+/// mistaken for a cycle.
+pub fn resolve_and_merge_extends(
+    configuration: &mut PartialConfiguration,
+    base_path: &Path,
+) -> Result<(), Error> {
+    let mut ancestors = Vec::new();
+    resolve_and_merge_extends_with_ancestors(configuration, base_path, &mut ancestors)
+}
+
+fn resolve_and_merge_extends_with_ancestors(
+    configuration: &mut PartialConfiguration,
+    base_path: &Path,
+    ancestors: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let Some(extends) = configuration.extends.clone() else {
+        return Ok(());
+    };
+
+    // Folded independently of `configuration`, so each entry's `Some` fields win over the
+    // previous entries' regardless of what `configuration` itself sets; `configuration`'s own
+    // fields are only applied once, at the end, so they win over every extended entry.
+    let mut accumulated = PartialConfiguration::default();
+
+    for specifier in extends.iter() {
+        let resolved_path = resolve_extend_specifier(specifier, base_path)?;
+
+        if ancestors.contains(&resolved_path) {
+            return Err(ConfigurationDiagnostic::cant_load_extend_file(
+                specifier.clone(),
+                "this `extends` entry creates a cycle",
+            )
+            .into());
+        }
+
+        let content = std::fs::read_to_string(&resolved_path).map_err(|error| {
+            ConfigurationDiagnostic::cant_load_extend_file(specifier.clone(), error.to_string())
+        })?;
+
+        let mut extended: PartialConfiguration = deserialize_from_json_str(
+            &content,
+            JsonParserOptions::default().with_allow_comments(),
+            specifier,
+        )
+        .into_deserialized()
+        .map_err(|error| {
+            ConfigurationDiagnostic::cant_load_extend_file(specifier.clone(), format!("{error:?}"))
+        })?;
+
+        let extend_base_path = resolved_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| base_path.to_path_buf());
+        ancestors.push(resolved_path);
+        let result =
+            resolve_and_merge_extends_with_ancestors(&mut extended, &extend_base_path, ancestors);
+        ancestors.pop();
+        result?;
+
+        accumulated.merge_with(extended);
+    }
+
+    accumulated.merge_with(configuration.clone());
+    *configuration = accumulated;
+
+    Ok(())
+}
+
+/// Resolves a single `extends` entry to an absolute path on disk.
+///
+/// Entries starting with `.` or `/` are treated as relative/absolute paths. Everything else is
+/// resolved as a Node package specifier, honoring the target package's `exports` map.
+fn resolve_extend_specifier(specifier: &str, base_path: &Path) -> Result<PathBuf, Error> {
+    if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+        return base_path.join(specifier).canonicalize().map_err(|error| {
+            ConfigurationDiagnostic::cant_load_extend_file(specifier, error.to_string()).into()
+        });
+    }
+
+    let resolver = Resolver::new(ResolveOptions {
+        condition_names: RESOLVE_CONDITIONS.iter().map(|s| s.to_string()).collect(),
+        extensions: vec![".json".to_string(), ".jsonc".to_string()],
+        ..ResolveOptions::default()
+    });
+
+    resolver
+        .resolve(base_path, specifier)
+        .map(|resolution| resolution.into_path_buf())
+        .map_err(|error| {
+            ConfigurationDiagnostic::cant_load_extend_file(specifier, error.to_string()).into()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Creates a fresh, empty temp directory for a single test, so parallel tests don't trip
+    /// over each other's fixtures.
+    fn temp_dir_for(test_name: &str) -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("biome-extends-test-{test_name}-{id}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn parse(content: &str) -> PartialConfiguration {
+        deserialize_from_json_str(
+            content,
+            JsonParserOptions::default().with_allow_comments(),
+            "biome.json",
+        )
+        .into_deserialized()
+        .unwrap()
+    }
+
+    #[test]
+    fn relative_extends_are_not_treated_as_package_specifiers() {
+        assert!("./biome.json".starts_with('.'));
+        assert!("../shared/biome.jsonc".starts_with('.'));
+        assert!(!"@my-org/biome-config/recommended".starts_with('.'));
+    }
+
+    #[test]
+    fn relative_extends_entry_is_resolved_and_merged() {
+        let dir = temp_dir_for("relative-merge");
+        std::fs::write(
+            dir.join("shared.json"),
+            r#"{ "linter": { "rules": { "all": true } } }"#,
+        )
+        .unwrap();
+
+        let mut configuration = parse(r#"{ "extends": ["./shared.json"] }"#);
+        resolve_and_merge_extends(&mut configuration, &dir).unwrap();
+
+        assert_eq!(
+            configuration
+                .linter
+                .unwrap()
+                .rules
+                .unwrap()
+                .all,
+            Some(true)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn extended_jsonc_file_with_comments_is_parsed() {
+        let dir = temp_dir_for("jsonc-comments");
+        std::fs::write(
+            dir.join("shared.jsonc"),
+            "// shared defaults\n{ \"linter\": { \"enabled\": true } }",
+        )
+        .unwrap();
+
+        let mut configuration = parse(r#"{ "extends": ["./shared.jsonc"] }"#);
+        resolve_and_merge_extends(&mut configuration, &dir).unwrap();
+
+        assert_eq!(configuration.linter.unwrap().enabled, Some(true));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_cycle_between_extended_files_is_rejected() {
+        let dir = temp_dir_for("cycle");
+        std::fs::write(dir.join("a.json"), r#"{ "extends": ["./b.json"] }"#).unwrap();
+        std::fs::write(dir.join("b.json"), r#"{ "extends": ["./a.json"] }"#).unwrap();
+
+        let mut configuration = parse(r#"{ "extends": ["./a.json"] }"#);
+        let result = resolve_and_merge_extends(&mut configuration, &dir);
+
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn later_extends_entries_win_over_earlier_ones() {
+        let dir = temp_dir_for("multi-entry-precedence");
+        std::fs::write(
+            dir.join("a.json"),
+            r#"{ "linter": { "rules": { "all": true, "recommended": true } } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b.json"),
+            r#"{ "linter": { "rules": { "all": false } } }"#,
+        )
+        .unwrap();
+
+        let mut configuration = parse(r#"{ "extends": ["./a.json", "./b.json"] }"#);
+        resolve_and_merge_extends(&mut configuration, &dir).unwrap();
+
+        let rules = configuration.linter.unwrap().rules.unwrap();
+        // b.json is listed after a.json, so its `all: false` wins...
+        assert_eq!(rules.all, Some(false));
+        // ...but a.json's `recommended: true` is untouched by b.json and survives.
+        assert_eq!(rules.recommended, Some(true));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn the_root_configuration_wins_over_every_extends_entry() {
+        let dir = temp_dir_for("root-wins");
+        std::fs::write(
+            dir.join("shared.json"),
+            r#"{ "linter": { "enabled": false } }"#,
+        )
+        .unwrap();
+
+        let mut configuration =
+            parse(r#"{ "extends": ["./shared.json"], "linter": { "enabled": true } }"#);
+        resolve_and_merge_extends(&mut configuration, &dir).unwrap();
+
+        assert_eq!(configuration.linter.unwrap().enabled, Some(true));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_diamond_of_shared_extends_is_not_treated_as_a_cycle() {
+        let dir = temp_dir_for("diamond");
+        std::fs::write(
+            dir.join("base.json"),
+            r#"{ "linter": { "rules": { "recommended": true } } }"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("a.json"), r#"{ "extends": ["./base.json"] }"#).unwrap();
+        std::fs::write(dir.join("b.json"), r#"{ "extends": ["./base.json"] }"#).unwrap();
+
+        let mut configuration = parse(r#"{ "extends": ["./a.json", "./b.json"] }"#);
+        let result = resolve_and_merge_extends(&mut configuration, &dir);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            configuration.linter.unwrap().rules.unwrap().recommended,
+            Some(true)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn npm_specifier_extends_entry_is_resolved_through_package_exports() {
+        let dir = temp_dir_for("npm-specifier");
+        let package_dir = dir.join("node_modules").join("@my-org").join("biome-config");
+        std::fs::create_dir_all(&package_dir).unwrap();
+        std::fs::write(
+            package_dir.join("package.json"),
+            r#"{ "name": "@my-org/biome-config", "exports": { "./recommended": "./recommended.json" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            package_dir.join("recommended.json"),
+            r#"{ "linter": { "enabled": true } }"#,
+        )
+        .unwrap();
+
+        let resolved =
+            resolve_extend_specifier("@my-org/biome-config/recommended", &dir).unwrap();
+
+        assert_eq!(
+            resolved,
+            package_dir.join("recommended.json").canonicalize().unwrap()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+use biome_deserialize::StringSet;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// The indent style, shared by every language-specific formatter section.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PlainIndentStyle {
+    /// Tab
+    #[default]
+    Tab,
+    /// Space
+    Space,
+}
+
+/// The configuration of the formatter
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct FormatterConfiguration {
+    /// if `false`, it disables the feature and the formatter won't be executed. `true` by default
+    #[partial(bpaf(long("formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied by default to every language.
+    #[partial(bpaf(long("indent-style"), argument("tab|space"), optional))]
+    pub indent_style: PlainIndentStyle,
+
+    /// A list of Unix shell style patterns. The included files will be formatted
+    #[partial(bpaf(hide))]
+    pub include: StringSet,
+
+    /// A list of Unix shell style patterns. The excluded files will not be formatted
+    #[partial(bpaf(hide))]
+    pub ignore: StringSet,
+}
+
+impl Default for FormatterConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: PlainIndentStyle::Tab,
+            include: Default::default(),
+            ignore: Default::default(),
+        }
+    }
+}
+
+impl PartialFormatterConfiguration {
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.enabled, Some(false))
+    }
+
+    pub fn get_formatter_configuration(&self) -> FormatterConfiguration {
+        FormatterConfiguration {
+            enabled: self.enabled.unwrap_or(true),
+            indent_style: self.indent_style.unwrap_or_default(),
+            include: self.include.clone().unwrap_or_default(),
+            ignore: self.ignore.clone().unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Generated by `xtask/codegen`, don't manually modify this file.
+
+use crate::linter::Rules;
+use biome_analyze::AnalyzerRules;
+
+/// Pushes the rules enabled by `rules` into `analyzer_rules`: every rule when `all` is `true`,
+/// otherwise the recommended rules unless `recommended` is `false`. Per-rule severities and
+/// options are not read.
+pub fn push_to_analyzer_rules(rules: &Rules, analyzer_rules: &mut AnalyzerRules) {
+    if rules.all.unwrap_or(false) {
+        analyzer_rules.push_all();
+    } else if rules.recommended.unwrap_or(true) {
+        analyzer_rules.push_recommended();
+    }
+}
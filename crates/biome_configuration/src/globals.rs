@@ -0,0 +1,197 @@
+//! Ambient globals known to the analyzer, following how `oxlint` models `env` and `globals`.
+//!
+//! [Globals] is a flat, user-authored map of identifier name to [GlobalValue]. [Env] is a set of
+//! named presets (`"browser"`, `"node"`, `"es2021"`, `"worker"`, `"jest"`, ...), each of which
+//! expands into a built-in [Globals] map through [Env::expand_presets]. Presets are additive: a
+//! user-provided `globals` entry of `"off"` removes the name from the resolved map, whether a
+//! preset or an earlier `globals` map contributed it.
+
+use biome_deserialize::Merge;
+use biome_deserialize_macros::Deserializable;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// Whether an ambient global can be reassigned, only read, or is disabled.
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum GlobalValue {
+    /// The identifier is known, but rules must not suggest reassigning it.
+    Readonly,
+    /// The identifier is known and can be reassigned.
+    Writable,
+    /// The identifier is not known, even if an [Env] preset would otherwise provide it.
+    Off,
+}
+
+/// A map of ambient identifier name to [GlobalValue].
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Globals(pub FxHashMap<String, GlobalValue>);
+
+impl Globals {
+    /// Merges `other` into `self`. Entries in `other` take precedence, and a [GlobalValue::Off]
+    /// entry removes the name from the resulting map entirely rather than being kept as a
+    /// tombstone.
+    pub fn merge_with(&mut self, other: Globals) {
+        for (name, value) in other.0 {
+            match value {
+                GlobalValue::Off => {
+                    self.0.remove(&name);
+                }
+                _ => {
+                    self.0.insert(name, value);
+                }
+            }
+        }
+    }
+}
+
+/// `globals` is a plain field of `LinterConfiguration`/`JavascriptConfiguration`, both of which
+/// derive `Merge`, so `Globals` itself must implement the trait. A derived, wholesale-replace
+/// `Merge` would defeat the whole point of `"off"`-aware composition across `extends`/
+/// `overrides` layers, so entries are merged one by one, later layers winning. Unlike
+/// [Globals::merge_with], a [GlobalValue::Off] entry is kept, so that it can still remove a
+/// preset-provided name once [Env::expand_presets] is merged with the composed map.
+impl Merge for Globals {
+    fn merge_with(&mut self, other: Globals) {
+        self.0.extend(other.0);
+    }
+}
+
+/// A named, built-in preset of ambient globals (mirrors oxlint's `env`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EnvPreset {
+    Browser,
+    Node,
+    Es2021,
+    Worker,
+    Jest,
+}
+
+impl EnvPreset {
+    /// The ambient globals contributed by this preset, all [GlobalValue::Readonly] unless noted.
+    fn globals(self) -> &'static [(&'static str, GlobalValue)] {
+        match self {
+            EnvPreset::Browser => &[
+                ("window", GlobalValue::Readonly),
+                ("document", GlobalValue::Readonly),
+                ("navigator", GlobalValue::Readonly),
+                ("location", GlobalValue::Readonly),
+            ],
+            EnvPreset::Node => &[
+                ("process", GlobalValue::Readonly),
+                ("require", GlobalValue::Readonly),
+                ("module", GlobalValue::Writable),
+                ("exports", GlobalValue::Writable),
+                ("__dirname", GlobalValue::Readonly),
+                ("__filename", GlobalValue::Readonly),
+            ],
+            EnvPreset::Es2021 => &[
+                ("globalThis", GlobalValue::Readonly),
+                ("Promise", GlobalValue::Readonly),
+                ("WeakRef", GlobalValue::Readonly),
+            ],
+            EnvPreset::Worker => &[
+                ("self", GlobalValue::Readonly),
+                ("importScripts", GlobalValue::Readonly),
+                ("postMessage", GlobalValue::Readonly),
+            ],
+            EnvPreset::Jest => &[
+                ("describe", GlobalValue::Readonly),
+                ("it", GlobalValue::Readonly),
+                ("test", GlobalValue::Readonly),
+                ("expect", GlobalValue::Readonly),
+                ("beforeEach", GlobalValue::Readonly),
+                ("afterEach", GlobalValue::Readonly),
+            ],
+        }
+    }
+}
+
+/// The set of [EnvPreset]s enabled for a project. Every field is optional, like [crate::linter::Rules],
+/// so that one `extends`/`overrides` layer can enable `node` without having to repeat (or
+/// accidentally disable) a preset a base config already enabled.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Env {
+    pub browser: Option<bool>,
+    pub node: Option<bool>,
+    pub es2021: Option<bool>,
+    pub worker: Option<bool>,
+    pub jest: Option<bool>,
+}
+
+impl Env {
+    /// Expands every preset flagged `true` into a single, additive [Globals] map.
+    pub fn expand_presets(&self) -> Globals {
+        let mut globals = Globals::default();
+        let presets = [
+            (self.browser, EnvPreset::Browser),
+            (self.node, EnvPreset::Node),
+            (self.es2021, EnvPreset::Es2021),
+            (self.worker, EnvPreset::Worker),
+            (self.jest, EnvPreset::Jest),
+        ];
+        for (enabled, preset) in presets {
+            if enabled.unwrap_or(false) {
+                for (name, value) in preset.globals() {
+                    globals.0.insert((*name).to_string(), *value);
+                }
+            }
+        }
+        globals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn off_removes_a_preset_provided_global() {
+        let mut resolved = Env {
+            browser: Some(true),
+            ..Default::default()
+        }
+        .expand_presets();
+        assert_eq!(resolved.0.get("window"), Some(&GlobalValue::Readonly));
+
+        let mut user_globals = Globals::default();
+        user_globals.0.insert("window".to_string(), GlobalValue::Off);
+        resolved.merge_with(user_globals);
+
+        assert_eq!(resolved.0.get("window"), None);
+    }
+
+    #[test]
+    fn presets_are_additive() {
+        let resolved = Env {
+            browser: Some(true),
+            node: Some(true),
+            ..Default::default()
+        }
+        .expand_presets();
+        assert!(resolved.0.contains_key("window"));
+        assert!(resolved.0.contains_key("process"));
+    }
+
+    #[test]
+    fn merging_envs_keeps_the_base_presets() {
+        let mut base = Env {
+            browser: Some(true),
+            ..Default::default()
+        };
+        let extension = Env {
+            node: Some(true),
+            ..Default::default()
+        };
+        base.merge_with(extension);
+
+        assert_eq!(base.browser, Some(true));
+        assert_eq!(base.node, Some(true));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::globals::Globals;
+use crate::formatter::PlainIndentStyle;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Specific configuration for the JavaScript language
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JavascriptConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_javascript_formatter), optional))]
+    pub formatter: JavascriptFormatter,
+
+    /// A map of ambient identifiers (e.g. globals injected by a bundler) that JavaScript- and
+    /// TypeScript-specific rules, such as `noUndeclaredVariables`, should treat as known. Merged
+    /// with the globals resolved from [crate::linter::LinterConfiguration::env] and
+    /// [crate::linter::LinterConfiguration::globals] before being handed to the analyzer.
+    #[partial(bpaf(hide))]
+    pub globals: Globals,
+
+    /// JSX-related settings. When left unset and a sibling `tsconfig.json`/`jsconfig.json` is
+    /// found, these are seeded from its `compilerOptions.jsx*` fields, see
+    /// [crate::tsconfig::resolve_jsx_configuration_from_directory].
+    #[partial(type, bpaf(external(partial_jsx_configuration), optional))]
+    pub jsx: JsxConfiguration,
+}
+
+/// The JSX transform to assume when emitting or analyzing JSX, mirroring TypeScript's
+/// `compilerOptions.jsx`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum JsxRuntime {
+    /// The automatic, "transparent" runtime introduced in React 17 (`jsx`/`jsxs` factories,
+    /// no need for an `import React` pragma). Matches `"jsx": "react-jsx"` in `tsconfig.json`.
+    #[default]
+    Transparent,
+    /// The classic runtime, which desugars to calls to [JsxConfiguration::jsx_factory] and
+    /// requires the pragma to be in scope. Matches `"jsx": "react"` in `tsconfig.json`.
+    ReactClassic,
+}
+
+/// JSX runtime and import-source settings, analogous to Deno's `EmitConfigOptions`.
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsxConfiguration {
+    /// The JSX runtime to assume. Defaults to [JsxRuntime::Transparent].
+    #[partial(bpaf(long("javascript-jsx-runtime"), argument("transparent|react-classic"), optional))]
+    pub jsx_runtime: JsxRuntime,
+
+    /// The module specifier used to import the JSX factory when [Self::jsx_runtime] is
+    /// [JsxRuntime::Transparent], e.g. `"react"` or `"preact"`. Matches
+    /// `compilerOptions.jsxImportSource`.
+    #[partial(bpaf(hide))]
+    pub jsx_import_source: String,
+
+    /// The function called to create a JSX element when [Self::jsx_runtime] is
+    /// [JsxRuntime::ReactClassic]. Matches `compilerOptions.jsxFactory`. Defaults to
+    /// `"React.createElement"`.
+    #[partial(bpaf(hide))]
+    pub jsx_factory: String,
+
+    /// The function called to create a JSX fragment when [Self::jsx_runtime] is
+    /// [JsxRuntime::ReactClassic]. Matches `compilerOptions.jsxFragmentFactory`. Defaults to
+    /// `"React.Fragment"`.
+    #[partial(bpaf(hide))]
+    pub jsx_fragment_factory: String,
+}
+
+impl Default for JsxConfiguration {
+    fn default() -> Self {
+        Self {
+            jsx_runtime: JsxRuntime::Transparent,
+            jsx_import_source: "react".to_string(),
+            jsx_factory: "React.createElement".to_string(),
+            jsx_fragment_factory: "React.Fragment".to_string(),
+        }
+    }
+}
+
+/// Formatting options specific to the JavaScript files
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JavascriptFormatter {
+    /// Whether to insert spaces around brackets in object literals. Defaults to `true`.
+    #[partial(bpaf(long("javascript-formatter-bracket-spacing"), argument("true|false"), optional))]
+    pub bracket_spacing: bool,
+
+    /// The indent style applied to JavaScript (and its super languages) files.
+    #[partial(bpaf(long("javascript-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: PlainIndentStyle,
+}
+
+impl Default for JavascriptFormatter {
+    fn default() -> Self {
+        Self {
+            bracket_spacing: true,
+            indent_style: PlainIndentStyle::Tab,
+        }
+    }
+}
+
+impl JavascriptFormatter {
+    pub fn get_formatter_configuration(&self) -> Self {
+        self.clone()
+    }
+}
@@ -0,0 +1,82 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Specific configuration for the JSON language
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsonConfiguration {
+    /// Parsing options
+    #[partial(type, bpaf(external(partial_json_parser_configuration), optional))]
+    pub parser: JsonParserConfiguration,
+
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_json_formatter), optional))]
+    pub formatter: JsonFormatter,
+}
+
+/// Options that changes how the JSON parser behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsonParserConfiguration {
+    /// Allow parsing comments in `.json` files
+    #[partial(
+        bpaf(long("json-parser-allow-comments"), argument("true|false"), optional)
+    )]
+    pub allow_comments: bool,
+
+    /// Allow parsing trailing commas in `.json` files
+    #[partial(
+        bpaf(long("json-parser-allow-trailing-commas"), argument("true|false"), optional)
+    )]
+    pub allow_trailing_commas: bool,
+}
+
+impl Default for JsonParserConfiguration {
+    fn default() -> Self {
+        Self {
+            allow_comments: false,
+            allow_trailing_commas: false,
+        }
+    }
+}
+
+impl PartialJsonParserConfiguration {
+    pub fn get_parser_configuration(&self) -> JsonParserConfiguration {
+        JsonParserConfiguration {
+            allow_comments: self.allow_comments.unwrap_or_default(),
+            allow_trailing_commas: self.allow_trailing_commas.unwrap_or_default(),
+        }
+    }
+}
+
+/// Formatting options specific to the JSON files
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JsonFormatter {
+    /// Whether to insert spaces around brackets in object literals. Defaults to `true`.
+    #[partial(bpaf(long("json-formatter-bracket-spacing"), argument("true|false"), optional))]
+    pub bracket_spacing: bool,
+}
+
+impl Default for JsonFormatter {
+    fn default() -> Self {
+        Self {
+            bracket_spacing: true,
+        }
+    }
+}
+
+impl PartialJsonFormatter {
+    pub fn get_formatter_configuration(&self) -> JsonFormatter {
+        JsonFormatter {
+            bracket_spacing: self.bracket_spacing.unwrap_or(true),
+        }
+    }
+}
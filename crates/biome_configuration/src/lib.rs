@@ -4,19 +4,25 @@
 //! by language. The language might further options divided by tool.
 pub mod css;
 pub mod diagnostics;
+pub mod extends;
 pub mod formatter;
 pub mod generated;
+pub mod globals;
 pub mod javascript;
 pub mod json;
 pub mod linter;
 pub mod organize_imports;
 mod overrides;
+pub mod tsconfig;
 pub mod vcs;
 
 pub use crate::diagnostics::CantLoadExtendFile;
 pub use crate::diagnostics::ConfigurationDiagnostic;
+pub use crate::extends::resolve_and_merge_extends;
 pub use crate::generated::push_to_analyzer_rules;
+pub use crate::globals::{Env, GlobalValue, Globals};
 use crate::organize_imports::{partial_organize_imports, OrganizeImports, PartialOrganizeImports};
+pub use crate::vcs::{find_vcs_root, VcsClientKind, VcsIgnoreMatcher};
 use crate::vcs::{partial_vcs_configuration, PartialVcsConfiguration, VcsConfiguration};
 use biome_deserialize::{Deserialized, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
@@ -30,25 +36,26 @@ pub use formatter::{
     PlainIndentStyle,
 };
 pub use javascript::{
-    partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
-    PartialJavascriptConfiguration, PartialJavascriptFormatter,
+    partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter, JsxConfiguration,
+    JsxRuntime, PartialJavascriptConfiguration, PartialJavascriptFormatter, PartialJsxConfiguration,
 };
 pub use json::{
-    partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
-    PartialJsonFormatter,
+    partial_json_configuration, JsonConfiguration, JsonFormatter, JsonParserConfiguration,
+    PartialJsonConfiguration, PartialJsonFormatter, PartialJsonParserConfiguration,
 };
 pub use linter::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
     RuleConfiguration, RulePlainConfiguration, RuleWithOptions, Rules,
 };
 pub use overrides::{
-    OverrideFormatterConfiguration, OverrideLinterConfiguration,
+    OverrideFormatterConfiguration, OverrideJavascriptConfiguration, OverrideJsonConfiguration,
+    OverrideJsonParserConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Limit the size of files to 1.0 MiB by default
 pub const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
@@ -102,6 +109,11 @@ pub struct Configuration {
     pub css: CssConfiguration,
 
     /// A list of paths to other JSON files, used to extends the current configuration.
+    /// Each entry can either be a relative path to another `biome.json`/`biome.jsonc` file, or a
+    /// bare npm specifier (e.g. `"@my-org/biome-config/recommended"`) resolved from
+    /// [ConfigurationPayload::external_resolution_base_path] using the target package's
+    /// `exports` map, the same way an ESLint or oxlint shareable config would be resolved. See
+    /// [resolve_and_merge_extends] for how entries are resolved and merged.
     #[partial(bpaf(hide))]
     pub extends: StringSet,
 
@@ -153,6 +165,16 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    /// Returns the effective [JsonParserConfiguration], used by the JSON language handler to
+    /// decide whether `.json`/`.jsonc` source files may contain comments or trailing commas.
+    pub fn get_json_parser_configuration(&self) -> JsonParserConfiguration {
+        self.json
+            .as_ref()
+            .and_then(|json| json.parser.as_ref())
+            .map(|parser| parser.get_parser_configuration())
+            .unwrap_or_default()
+    }
+
     pub fn get_json_formatter_configuration(&self) -> JsonFormatter {
         self.json
             .as_ref()
@@ -176,12 +198,42 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    /// Returns the ambient globals the analyzer should know about: every [linter::Env] preset
+    /// enabled, merged with `linter.globals`, merged with the JavaScript-specific
+    /// `javascript.globals`. Entries listed later win, so a JS-specific `"off"` can override a
+    /// preset-provided global.
+    pub fn get_resolved_globals(&self) -> Globals {
+        let mut resolved = self
+            .linter
+            .as_ref()
+            .map(|linter| linter.get_resolved_globals())
+            .unwrap_or_default();
+        if let Some(javascript_globals) = self.javascript.as_ref().and_then(|js| js.globals.clone())
+        {
+            resolved.merge_with(javascript_globals);
+        }
+        resolved
+    }
+
     pub fn is_organize_imports_disabled(&self) -> bool {
         self.organize_imports
             .as_ref()
             .map_or(false, |f| f.is_disabled())
     }
 
+    /// The module specifier organize-imports should use for a pragma-less auto-added JSX import,
+    /// resolved from `javascript.jsx`. See [organize_imports::OrganizeImports::jsx_import_source].
+    pub fn get_organize_imports_jsx_import_source(&self) -> Option<String> {
+        let jsx = self.javascript.as_ref()?.jsx.as_ref()?;
+        let jsx = JsxConfiguration {
+            jsx_runtime: jsx.jsx_runtime.unwrap_or_default(),
+            jsx_import_source: jsx.jsx_import_source.clone().unwrap_or_default(),
+            jsx_factory: jsx.jsx_factory.clone().unwrap_or_default(),
+            jsx_fragment_factory: jsx.jsx_fragment_factory.clone().unwrap_or_default(),
+        };
+        OrganizeImports::jsx_import_source(&jsx).map(ToString::to_string)
+    }
+
     pub fn is_vcs_disabled(&self) -> bool {
         self.vcs.as_ref().map_or(true, |f| f.is_disabled())
     }
@@ -189,6 +241,42 @@ impl PartialConfiguration {
     pub fn is_vcs_enabled(&self) -> bool {
         !self.is_vcs_disabled()
     }
+
+    /// Returns the effective `files.ignore` patterns: whatever [crate::vcs::VcsIgnoreMatcher]
+    /// discovers when [VcsConfiguration::use_ignore_file] is enabled, followed by the
+    /// user-provided list from [FilesConfiguration::ignore]. Patterns are meant to be evaluated
+    /// in order with later entries overriding earlier ones, mirroring gitignore's own
+    /// last-match-wins precedence, so `files.ignore` must come *last* to actually take precedence
+    /// over the auto-discovered VCS patterns, not first.
+    pub fn get_effective_ignore_patterns(&self, workspace_root: &Path) -> Vec<String> {
+        let mut patterns = Vec::new();
+
+        let vcs = self.vcs.as_ref();
+        if vcs.and_then(|vcs| vcs.use_ignore_file).unwrap_or_default() {
+            let client_kind = vcs.and_then(|vcs| vcs.client_kind).unwrap_or_default();
+            let root = vcs
+                .and_then(|vcs| vcs.root.clone())
+                .filter(|root| !root.is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| crate::vcs::find_vcs_root(workspace_root, client_kind));
+
+            if let Ok(matcher) =
+                crate::vcs::VcsIgnoreMatcher::discover(&root, workspace_root, client_kind)
+            {
+                patterns.extend(matcher.into_patterns());
+            }
+        }
+
+        patterns.extend(
+            self.files
+                .as_ref()
+                .and_then(|files| files.ignore.clone())
+                .map(|ignore| ignore.iter().cloned().collect::<Vec<_>>())
+                .unwrap_or_default(),
+        );
+
+        patterns
+    }
 }
 
 /// The configuration of the filesystem
@@ -203,7 +291,8 @@ pub struct FilesConfiguration {
     pub max_size: NonZeroU64,
 
     /// A list of Unix shell style patterns. Biome will ignore files/folders that will
-    /// match these patterns.
+    /// match these patterns. When [VcsConfiguration::use_ignore_file] is enabled, the patterns
+    /// discovered in the VCS ignore files are composed with this list rather than replacing it.
     #[partial(bpaf(hide))]
     pub ignore: StringSet,
 
@@ -228,6 +317,32 @@ impl Default for FilesConfiguration {
     }
 }
 
+/// Parses the contents of a `biome.json`/`biome.jsonc` configuration file found at
+/// `configuration_file_path`. Comments and trailing commas are allowed when the file name ends
+/// in `.jsonc`, regardless of the project's own [JsonConfiguration::parser] section, since that
+/// section only governs how *other* `.json`/`.jsonc` source files in the project are handled.
+/// Unknown fields are always rejected, see `#[partial(serde(deny_unknown_fields))]` on
+/// [Configuration].
+pub fn parse_configuration(
+    content: &str,
+    configuration_file_path: &Path,
+) -> Deserialized<PartialConfiguration> {
+    let is_jsonc = configuration_file_path
+        .extension()
+        .is_some_and(|extension| extension == "jsonc");
+
+    let mut options = biome_json_parser::JsonParserOptions::default();
+    if is_jsonc {
+        options = options.with_allow_comments().with_allow_trailing_commas();
+    }
+
+    biome_deserialize::json::deserialize_from_json_str(
+        content,
+        options,
+        &configuration_file_path.to_string_lossy(),
+    )
+}
+
 pub struct ConfigurationPayload {
     /// The result of the deserialization
     pub deserialized: Deserialized<PartialConfiguration>,
@@ -237,6 +352,37 @@ pub struct ConfigurationPayload {
     pub external_resolution_base_path: PathBuf,
 }
 
+impl ConfigurationPayload {
+    /// Resolves every entry of `extends` found in [Self::deserialized] against
+    /// [Self::external_resolution_base_path], and merges them into the deserialized
+    /// configuration in declaration order.
+    pub fn resolve_extends(&mut self) -> Result<(), biome_diagnostics::Error> {
+        let base_path = self.external_resolution_base_path.clone();
+        crate::extends::resolve_and_merge_extends(self.deserialized.as_mut(), &base_path)
+    }
+
+    /// Seeds `javascript.jsx` from a sibling `tsconfig.json`/`jsconfig.json`, for every field the
+    /// user didn't already set explicitly in `biome.json`.
+    pub fn seed_jsx_configuration_from_tsconfig(&mut self) {
+        let directory = self.configuration_file_path.parent().unwrap_or(Path::new("."));
+        let Some(tsconfig_jsx) = crate::tsconfig::resolve_jsx_configuration_from_directory(directory)
+        else {
+            return;
+        };
+
+        let configuration = self.deserialized.as_mut();
+        let javascript = configuration.javascript.get_or_insert_with(Default::default);
+        let jsx = javascript.jsx.get_or_insert_with(Default::default);
+        jsx.jsx_runtime = jsx.jsx_runtime.or(tsconfig_jsx.jsx_runtime);
+        jsx.jsx_import_source = jsx.jsx_import_source.take().or(tsconfig_jsx.jsx_import_source);
+        jsx.jsx_factory = jsx.jsx_factory.take().or(tsconfig_jsx.jsx_factory);
+        jsx.jsx_fragment_factory = jsx
+            .jsx_fragment_factory
+            .take()
+            .or(tsconfig_jsx.jsx_fragment_factory);
+    }
+}
+
 #[derive(Debug, Default, PartialEq)]
 pub enum ConfigurationPathHint {
     /// The default mode, not having a configuration file is not an error.
@@ -0,0 +1,108 @@
+use crate::globals::{Env, Globals};
+use biome_deserialize::StringSet;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// The configuration of a single rule: its severity.
+pub type RuleConfiguration = RulePlainConfiguration;
+
+/// Normalized representation of a rule that only carries its severity.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RulePlainConfiguration {
+    Off,
+    Warn,
+    Error,
+}
+
+/// A rule entry that also carries rule-specific options.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleWithOptions {
+    pub level: RulePlainConfiguration,
+}
+
+/// The rules enabled for the linter, grouped by category. Every field is optional so that a
+/// `biome.json` only has to mention the rules it wants to turn on or off; unset fields fall back
+/// to whatever `recommended`/`all` imply.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Rules {
+    /// It enables the lint rules recommended by Biome. `true` by default.
+    pub recommended: Option<bool>,
+
+    /// It enables ALL rules. The rules that belong to `nursery` aren't enabled.
+    pub all: Option<bool>,
+}
+
+/// The configuration of the linter
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct LinterConfiguration {
+    /// if `false`, it disables the feature and the linter won't be executed. `true` by default
+    #[partial(bpaf(long("linter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// List of rules
+    #[partial(bpaf(hide))]
+    pub rules: Rules,
+
+    /// A list of Unix shell style patterns. The included files will be run through the linter
+    #[partial(bpaf(hide))]
+    pub include: StringSet,
+
+    /// A list of Unix shell style patterns. The excluded files will not be run through the linter
+    #[partial(bpaf(hide))]
+    pub ignore: StringSet,
+
+    /// A map of ambient identifiers an analyzed program can access, alongside whether they can
+    /// be reassigned (`"writable"`), only read (`"readonly"`), or not (`"off"`, which removes an
+    /// identifier that a [LinterConfiguration::env] preset would otherwise contribute).
+    #[partial(bpaf(hide))]
+    pub globals: Globals,
+
+    /// Enables a named set of ambient globals (`"browser"`, `"node"`, `"es2021"`, `"worker"`,
+    /// `"jest"`, ...), the same way `env` works in ESLint/oxlint. Presets are additive and are
+    /// merged with [LinterConfiguration::globals] before being handed off to the analyzer.
+    #[partial(bpaf(hide))]
+    pub env: Env,
+}
+
+impl Default for LinterConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rules: Rules {
+                recommended: Some(true),
+                all: Some(false),
+            },
+            include: Default::default(),
+            ignore: Default::default(),
+            globals: Default::default(),
+            env: Default::default(),
+        }
+    }
+}
+
+impl PartialLinterConfiguration {
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.enabled, Some(false))
+    }
+
+    pub fn get_rules(&self) -> Rules {
+        self.rules.clone().unwrap_or_default()
+    }
+
+    /// Returns the fully expanded set of ambient globals: every preset listed in [Self::env]
+    /// merged with the explicit [Self::globals] map, with a `"off"` entry in `globals` able to
+    /// remove a name a preset contributed.
+    pub fn get_resolved_globals(&self) -> Globals {
+        let mut resolved = self.env.clone().unwrap_or_default().expand_presets();
+        resolved.merge_with(self.globals.clone().unwrap_or_default());
+        resolved
+    }
+}
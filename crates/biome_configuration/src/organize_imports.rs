@@ -0,0 +1,54 @@
+use biome_deserialize::StringSet;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the import sorting feature
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct OrganizeImports {
+    /// Enables the organization of imports
+    #[partial(bpaf(long("organize-imports-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// A list of Unix shell style patterns. The included files will be sorted
+    #[partial(bpaf(hide))]
+    pub include: StringSet,
+
+    /// A list of Unix shell style patterns. The excluded files will not be sorted
+    #[partial(bpaf(hide))]
+    pub ignore: StringSet,
+}
+
+impl Default for OrganizeImports {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            include: Default::default(),
+            ignore: Default::default(),
+        }
+    }
+}
+
+impl PartialOrganizeImports {
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.enabled, Some(false))
+    }
+}
+
+impl OrganizeImports {
+    /// The module specifier a pragma-less auto-added import should come from when organizing
+    /// imports for a JSX file, i.e. [crate::javascript::JsxConfiguration::jsx_import_source]
+    /// when [crate::javascript::JsxRuntime::Transparent] is in effect. The classic runtime
+    /// relies on an explicit pragma instead, so it has no configured import source to suggest.
+    pub fn jsx_import_source(jsx: &crate::javascript::JsxConfiguration) -> Option<&str> {
+        match jsx.jsx_runtime {
+            crate::javascript::JsxRuntime::Transparent if !jsx.jsx_import_source.is_empty() => {
+                Some(jsx.jsx_import_source.as_str())
+            }
+            _ => None,
+        }
+    }
+}
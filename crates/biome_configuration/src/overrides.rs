@@ -0,0 +1,107 @@
+use crate::javascript::JsxRuntime;
+use crate::linter::Rules;
+use biome_deserialize::StringSet;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// A list of granular patterns that should be applied only to a subset of files.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Overrides(pub Vec<OverridePattern>);
+
+/// A granular override applied to files matching `include`/`ignore`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct OverridePattern {
+    /// A list of Unix shell style patterns. The override will be applied to files that match
+    /// these patterns.
+    #[partial(bpaf(hide))]
+    pub include: StringSet,
+
+    /// A list of Unix shell style patterns. The override will not be applied to files that
+    /// match these patterns.
+    #[partial(bpaf(hide))]
+    pub ignore: StringSet,
+
+    /// Specific configuration for the formatter
+    #[partial(bpaf(hide))]
+    pub formatter: OverrideFormatterConfiguration,
+
+    /// Specific configuration for the linter
+    #[partial(bpaf(hide))]
+    pub linter: OverrideLinterConfiguration,
+
+    /// Specific configuration for organize imports
+    #[partial(bpaf(hide))]
+    pub organize_imports: OverrideOrganizeImportsConfiguration,
+
+    /// Specific configuration for the JavaScript language, e.g. setting `jsxRuntime` only for
+    /// `*.mdx` files
+    #[partial(bpaf(hide))]
+    pub javascript: OverrideJavascriptConfiguration,
+
+    /// Specific configuration for the JSON language, e.g. allowing comments only in
+    /// `tsconfig.json`
+    #[partial(bpaf(hide))]
+    pub json: OverrideJsonConfiguration,
+}
+
+/// Formatter configuration overridden for a specific set of files.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFormatterConfiguration {
+    pub enabled: Option<bool>,
+}
+
+/// Linter configuration overridden for a specific set of files.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideLinterConfiguration {
+    pub enabled: Option<bool>,
+    pub rules: Option<Rules>,
+}
+
+/// Organize imports configuration overridden for a specific set of files.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideOrganizeImportsConfiguration {
+    pub enabled: Option<bool>,
+}
+
+/// JavaScript-specific configuration overridden for a specific set of files, e.g. allowing
+/// `*.mdx` files to use the classic JSX runtime while the rest of the project uses the
+/// transparent one.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJavascriptConfiguration {
+    pub jsx_runtime: Option<JsxRuntime>,
+    pub jsx_import_source: Option<String>,
+    pub jsx_factory: Option<String>,
+    pub jsx_fragment_factory: Option<String>,
+}
+
+/// JSON-specific configuration overridden for a specific set of files, e.g. allowing comments
+/// and trailing commas only for `tsconfig.json`/`jsconfig.json`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJsonConfiguration {
+    pub parser: Option<OverrideJsonParserConfiguration>,
+}
+
+/// See [OverrideJsonConfiguration::parser].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Deserializable, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJsonParserConfiguration {
+    pub allow_comments: Option<bool>,
+    pub allow_trailing_commas: Option<bool>,
+}
@@ -0,0 +1,115 @@
+//! Seeds [crate::javascript::JsxConfiguration] defaults from a sibling `tsconfig.json` or
+//! `jsconfig.json`, mirroring the handful of `compilerOptions` fields Deno's `EmitConfigOptions`
+//! treats as significant for JSX: `jsx`, `jsxFactory`, `jsxFragmentFactory` and `jsxImportSource`.
+//! Values explicitly set in `biome.json` always take precedence over whatever a tsconfig seeds.
+
+use crate::javascript::{JsxRuntime, PartialJsxConfiguration};
+use serde::Deserialize;
+use std::path::Path;
+
+const TSCONFIG_FILE_NAMES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfig {
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompilerOptions {
+    jsx: Option<String>,
+    jsx_factory: Option<String>,
+    jsx_fragment_factory: Option<String>,
+    jsx_import_source: Option<String>,
+}
+
+/// Looks for `tsconfig.json`/`jsconfig.json` next to `directory`, and returns the
+/// [PartialJsxConfiguration] it implies, if any. Returns `None` when neither file is present,
+/// or when it doesn't mention any of the `jsx*` compiler options.
+pub fn resolve_jsx_configuration_from_directory(directory: &Path) -> Option<PartialJsxConfiguration> {
+    let content = TSCONFIG_FILE_NAMES
+        .iter()
+        .find_map(|file_name| std::fs::read_to_string(directory.join(file_name)).ok())?;
+    resolve_jsx_configuration_from_str(&content)
+}
+
+fn resolve_jsx_configuration_from_str(content: &str) -> Option<PartialJsxConfiguration> {
+    let tsconfig: TsConfig = jsonc_parser::parse_to_serde_value(content, &Default::default())
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_value(value).ok())?;
+    let options = tsconfig.compiler_options;
+
+    if options.jsx.is_none()
+        && options.jsx_factory.is_none()
+        && options.jsx_fragment_factory.is_none()
+        && options.jsx_import_source.is_none()
+    {
+        return None;
+    }
+
+    Some(PartialJsxConfiguration {
+        // "preserve" leaves JSX untransformed, so it doesn't imply either runtime; leave
+        // `jsx_runtime` unseeded rather than guessing. "react-native" desugars the same way the
+        // classic runtime does (calls to `jsx_factory`), just through a different bundler.
+        jsx_runtime: options.jsx.as_deref().and_then(|jsx| match jsx {
+            "react" | "react-native" => Some(JsxRuntime::ReactClassic),
+            "react-jsx" | "react-jsxdev" => Some(JsxRuntime::Transparent),
+            _ => None,
+        }),
+        jsx_factory: options.jsx_factory,
+        jsx_fragment_factory: options.jsx_fragment_factory,
+        jsx_import_source: options.jsx_import_source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_react_classic_from_jsx_factory() {
+        let config = resolve_jsx_configuration_from_str(
+            r#"{
+                "compilerOptions": {
+                    "jsx": "react",
+                    "jsxFactory": "h",
+                    "jsxFragmentFactory": "Fragment"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.jsx_runtime, Some(JsxRuntime::ReactClassic));
+        assert_eq!(config.jsx_factory.as_deref(), Some("h"));
+    }
+
+    #[test]
+    fn returns_none_without_jsx_options() {
+        assert!(resolve_jsx_configuration_from_str(r#"{ "compilerOptions": { "strict": true } }"#)
+            .is_none());
+    }
+
+    #[test]
+    fn preserve_does_not_seed_a_jsx_runtime() {
+        let config = resolve_jsx_configuration_from_str(
+            r#"{ "compilerOptions": { "jsx": "preserve", "jsxImportSource": "react" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.jsx_runtime, None);
+        assert_eq!(config.jsx_import_source.as_deref(), Some("react"));
+    }
+
+    #[test]
+    fn react_native_seeds_the_classic_runtime() {
+        let config = resolve_jsx_configuration_from_str(
+            r#"{ "compilerOptions": { "jsx": "react-native" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.jsx_runtime, Some(JsxRuntime::ReactClassic));
+    }
+}
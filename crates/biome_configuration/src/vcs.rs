@@ -0,0 +1,279 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Set of properties to integrate Biome with a VCS
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct VcsConfiguration {
+    /// Whether Biome should integrate itself with the VCS client
+    #[partial(bpaf(long("vcs-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The kind of client.
+    #[partial(bpaf(long("vcs-client-kind"), argument("KIND"), optional))]
+    pub client_kind: VcsClientKind,
+
+    /// Whether Biome should use the VCS ignore file. When [Self::use_ignore_file] is enabled,
+    /// Biome will discover and parse `.gitignore` (or the ignore file appropriate to
+    /// [Self::client_kind]) in every directory from [Self::root] down to the workspace
+    /// directory, and compose the resulting patterns with [crate::FilesConfiguration::ignore].
+    /// Ignore files in directories below the workspace directory are not read.
+    #[partial(bpaf(long("vcs-use-ignore-file"), argument("true|false"), optional))]
+    pub use_ignore_file: bool,
+
+    /// The folder where Biome should check for VCS files. When empty, Biome uses the nearest
+    /// ancestor of the workspace directory that contains [Self::client_kind]'s root marker
+    /// (e.g. `.git`), see [find_vcs_root], and the workspace directory itself if there is none.
+    #[partial(bpaf(long("vcs-root"), argument("PATH"), optional))]
+    pub root: String,
+}
+
+impl Default for VcsConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            client_kind: VcsClientKind::Git,
+            use_ignore_file: false,
+            root: String::new(),
+        }
+    }
+}
+
+impl PartialVcsConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default()
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        !self.is_enabled()
+    }
+}
+
+/// The VCS client Biome should integrate with.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum VcsClientKind {
+    #[default]
+    Git,
+    Mercurial,
+}
+
+impl VcsClientKind {
+    /// The name of the ignore file this client looks for, e.g. `.gitignore`.
+    pub const fn ignore_file_name(self) -> &'static str {
+        match self {
+            VcsClientKind::Git => ".gitignore",
+            VcsClientKind::Mercurial => ".hgignore",
+        }
+    }
+
+    /// The marker that identifies the root of a repository for this client, e.g. `.git`.
+    pub const fn root_marker(self) -> &'static str {
+        match self {
+            VcsClientKind::Git => ".git",
+            VcsClientKind::Mercurial => ".hg",
+        }
+    }
+}
+
+/// Walks upward from `start_directory`, looking for the directory that contains `client_kind`'s
+/// [VcsClientKind::root_marker] (e.g. `.git`). Falls back to `start_directory` itself if no
+/// ancestor has the marker, matching how a VCS client treats the working directory as the
+/// repository root when it can't find one.
+pub fn find_vcs_root(start_directory: &Path, client_kind: VcsClientKind) -> PathBuf {
+    let marker = client_kind.root_marker();
+    let mut directory = start_directory;
+    loop {
+        if directory.join(marker).exists() {
+            return directory.to_path_buf();
+        }
+        match directory.parent() {
+            Some(parent) => directory = parent,
+            None => return start_directory.to_path_buf(),
+        }
+    }
+}
+
+/// A single parsed line of an ignore file, in gitignore syntax: an optional `!` negation, and
+/// `trailing/` meaning the pattern only matches directories.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct IgnoreEntry {
+    pattern: String,
+    is_negated: bool,
+    is_dir_only: bool,
+    /// Depth (number of path components) of the ignore file the entry came from, relative to the
+    /// VCS root. Deeper entries override shallower ones for the same pattern, mirroring git's own
+    /// precedence rules.
+    depth: usize,
+    /// The directory the ignore file was found in, relative to the VCS root. A pattern from a
+    /// nested ignore file only applies under this directory, so it must stay scoped to it rather
+    /// than being treated as a repository-wide pattern.
+    directory: PathBuf,
+}
+
+/// An ignore matcher built by folding the ignore files found in each directory from the VCS root
+/// down to a start directory (see [Self::discover]), applying gitignore precedence semantics:
+/// deeper and later entries win, and `!`-negated entries re-include a path a previous pattern
+/// excluded. Ignore files in directories below the start directory are not read.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VcsIgnoreMatcher {
+    entries: Vec<IgnoreEntry>,
+}
+
+impl VcsIgnoreMatcher {
+    /// Walks from `vcs_root` down to `start_directory`, reading `client_kind`'s ignore file at
+    /// every level, and folds them into a single matcher. Directories deeper in the tree are
+    /// read, and therefore take precedence, after shallower ones.
+    pub fn discover(
+        vcs_root: &Path,
+        start_directory: &Path,
+        client_kind: VcsClientKind,
+    ) -> std::io::Result<Self> {
+        let mut matcher = Self::default();
+        let relative = start_directory.strip_prefix(vcs_root).unwrap_or(start_directory);
+
+        let mut directory = vcs_root.to_path_buf();
+        let mut relative_directory = PathBuf::new();
+        matcher.fold_ignore_file(&directory, &relative_directory, client_kind, 0)?;
+        for (depth, component) in relative.components().enumerate() {
+            directory.push(component);
+            relative_directory.push(component);
+            matcher.fold_ignore_file(&directory, &relative_directory, client_kind, depth + 1)?;
+        }
+
+        Ok(matcher)
+    }
+
+    fn fold_ignore_file(
+        &mut self,
+        directory: &Path,
+        relative_directory: &Path,
+        client_kind: VcsClientKind,
+        depth: usize,
+    ) -> std::io::Result<()> {
+        let ignore_file_path = directory.join(client_kind.ignore_file_name());
+        let content = match std::fs::read_to_string(&ignore_file_path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (is_negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let (is_dir_only, pattern) = match line.strip_suffix('/') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+
+            self.entries.push(IgnoreEntry {
+                pattern: pattern.to_string(),
+                is_negated,
+                is_dir_only,
+                depth,
+                directory: relative_directory.to_path_buf(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the Unix shell style patterns this matcher discovered, in precedence order
+    /// (shallower, earlier entries first). A pattern found in a nested ignore file is prefixed
+    /// with the directory it came from, so it stays scoped to that subtree instead of being
+    /// flattened into a repository-wide pattern. Negated (`!`) patterns are prefixed with `!`,
+    /// and directory-only patterns keep their trailing `/`, so they compose directly with
+    /// user-provided `files.ignore`/`files.include` glob lists instead of replacing them.
+    pub fn into_patterns(self) -> Vec<String> {
+        let mut entries = self.entries;
+        entries.sort_by_key(|entry| entry.depth);
+        entries
+            .into_iter()
+            .map(|entry| {
+                let mut pattern = String::new();
+                if entry.is_negated {
+                    pattern.push('!');
+                }
+                if entry.directory.as_os_str().is_empty() {
+                    pattern.push_str(&entry.pattern);
+                } else {
+                    pattern.push_str(&entry.directory.to_string_lossy());
+                    pattern.push('/');
+                    pattern.push_str(entry.pattern.trim_start_matches('/'));
+                }
+                if entry.is_dir_only {
+                    pattern.push('/');
+                }
+                pattern
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mercurial_uses_its_own_ignore_file_and_root_marker() {
+        assert_eq!(VcsClientKind::Mercurial.ignore_file_name(), ".hgignore");
+        assert_eq!(VcsClientKind::Mercurial.root_marker(), ".hg");
+    }
+
+    #[test]
+    fn deeper_entries_are_ordered_after_shallower_ones() {
+        let matcher = VcsIgnoreMatcher {
+            entries: vec![
+                IgnoreEntry {
+                    pattern: "dist".to_string(),
+                    is_negated: false,
+                    is_dir_only: true,
+                    depth: 2,
+                    directory: PathBuf::from("packages/app"),
+                },
+                IgnoreEntry {
+                    pattern: "*.log".to_string(),
+                    is_negated: false,
+                    is_dir_only: false,
+                    depth: 0,
+                    directory: PathBuf::new(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            matcher.into_patterns(),
+            vec!["*.log".to_string(), "packages/app/dist/".to_string()]
+        );
+    }
+
+    #[test]
+    fn find_vcs_root_walks_up_to_the_marker_directory() {
+        let temp = std::env::temp_dir().join(format!(
+            "biome-vcs-root-test-{:?}",
+            std::thread::current().id()
+        ));
+        let nested = temp.join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir_all(temp.join(".git")).unwrap();
+
+        let found = find_vcs_root(&nested, VcsClientKind::Git);
+
+        std::fs::remove_dir_all(&temp).unwrap();
+        assert_eq!(found, temp);
+    }
+}